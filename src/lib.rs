@@ -1,74 +1,298 @@
-use pyo3::exceptions::PyRuntimeError;
+use pyo3::create_exception;
+use pyo3::exceptions::PyException;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList, PyString};
+use pyo3::types::{PyAny, PyDict, PyList, PyString};
 use std::cell::RefCell;
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::RwLock;
 
+// ── Exception hierarchy ─────────────────────────────────────
+// Every failure path collapses into one of these instead of a single
+// opaque RuntimeError, so callers can `except` on the specific failure
+// (e.g. retry on EvaluationError but not on PolicyParseError).
+
+create_exception!(opa_eval, PolicyError, PyException, "Base class for all opa_eval errors.");
+create_exception!(opa_eval, PolicyLoadError, PolicyError, "A policy file could not be read from disk.");
+create_exception!(opa_eval, PolicyParseError, PolicyError, "A policy module failed to parse or compile.");
+create_exception!(opa_eval, PolicyNotLoadedError, PolicyError, "Evaluation was attempted before any policy was loaded.");
+create_exception!(opa_eval, EvaluationError, PolicyError, "Rule evaluation failed at runtime.");
+create_exception!(opa_eval, InputError, PolicyError, "The input or data was malformed JSON or could not be converted to JSON.");
+
+/// Structured error type threaded through the evaluation path, mirroring
+/// the variants of the Python exception hierarchy above. Keeping this as
+/// an enum (rather than a formatted String) lets each call site convert
+/// to the right exception class without re-classifying error messages.
+enum OpaError {
+    Load(String),
+    Parse(String),
+    NotLoaded,
+    Eval(String),
+    Input(String),
+}
+
+impl From<OpaError> for PyErr {
+    fn from(e: OpaError) -> PyErr {
+        match e {
+            OpaError::Load(msg) => PolicyLoadError::new_err(msg),
+            OpaError::Parse(msg) => PolicyParseError::new_err(msg),
+            OpaError::NotLoaded => PolicyNotLoadedError::new_err("call load_policy() first"),
+            OpaError::Eval(msg) => EvaluationError::new_err(msg),
+            OpaError::Input(msg) => InputError::new_err(msg),
+        }
+    }
+}
+
 // ── Policy config (shared, read-heavy) ──────────────────────
 
 struct PolicyConfig {
-    path: String,
-    source: String,
-    data_json: Option<String>,
+    // One entry per `add_policy`/`load_policy` call, in registration
+    // order: (path, source).
+    modules: Vec<(String, String)>,
+    data: Option<Input>,
     query: String,
 }
 
 static POLICY: RwLock<Option<PolicyConfig>> = RwLock::new(None);
-static POLICY_VERSION: AtomicU64 = AtomicU64::new(0);
+
+// Policy structure (which modules are loaded) and policy data are
+// versioned separately: data pushes are expected to be far more
+// frequent than rule changes, and re-parsing every `.rego` module on
+// every data push would defeat the point of the engine cache.
+static POLICY_GEN: AtomicU64 = AtomicU64::new(0);
+static DATA_GEN: AtomicU64 = AtomicU64::new(0);
+
+// Coverage instrumentation is baked into an Engine at build time, so it
+// is read by `build_engine` and toggling it bumps POLICY_GEN like any
+// other change that requires a full rebuild.
+static COVERAGE_ENABLED: AtomicBool = AtomicBool::new(false);
 
 // ── Thread-local engine cache ───────────────────────────────
 // Each thread keeps a ready-to-use Engine.  On evaluate() we only
 // call set_input_json + eval_rule — no policy parsing, no cloning.
-// The version counter invalidates caches when load_policy() is called.
+// The generation counters invalidate the cache: a policy-gen change
+// forces a full rebuild (reparse all modules); a data-gen-only change
+// just clears the data and re-adds the current data document on the
+// already-built engine.
 
 thread_local! {
-    static CACHED_ENGINE: RefCell<Option<(u64, regorus::Engine)>> = const { RefCell::new(None) };
+    static CACHED_ENGINE: RefCell<Option<(u64, u64, regorus::Engine)>> = const { RefCell::new(None) };
 }
 
-fn build_engine(cfg: &PolicyConfig) -> Result<regorus::Engine, String> {
+fn build_engine(cfg: &PolicyConfig) -> Result<regorus::Engine, OpaError> {
     let mut engine = regorus::Engine::new();
-    engine
-        .add_policy(cfg.path.clone(), cfg.source.clone())
-        .map_err(|e| format!("{e:#}"))?;
-    if let Some(ref data) = cfg.data_json {
+    engine.set_enable_coverage(COVERAGE_ENABLED.load(Ordering::Acquire));
+    for (path, source) in &cfg.modules {
         engine
-            .add_data_json(data)
-            .map_err(|e| format!("{e:#}"))?;
+            .add_policy(path.clone(), source.clone())
+            .map_err(|e| OpaError::Parse(format!("{e:#}")))?;
+    }
+    if let Some(ref data) = cfg.data {
+        add_data(&mut engine, data)?;
     }
     Ok(engine)
 }
 
-fn do_eval(input_json: &str) -> Result<String, String> {
+/// Make sure `slot` holds an engine that reflects `policy_ver`/`data_ver`,
+/// rebuilding or just refreshing data as cheaply as possible.
+fn refresh_engine(
+    slot: &mut Option<(u64, u64, regorus::Engine)>,
+    cfg: &PolicyConfig,
+    policy_ver: u64,
+    data_ver: u64,
+) -> Result<(), OpaError> {
+    match slot {
+        Some((pv, _, _)) if *pv != policy_ver => {
+            *slot = Some((policy_ver, data_ver, build_engine(cfg)?));
+        }
+        None => {
+            *slot = Some((policy_ver, data_ver, build_engine(cfg)?));
+        }
+        Some((_, dv, engine)) if *dv != data_ver => {
+            engine.clear_data();
+            if let Some(ref data) = cfg.data {
+                add_data(engine, data)?;
+            }
+            *dv = data_ver;
+        }
+        Some(_) => {}
+    }
+    Ok(())
+}
+
+/// A caller-supplied JSON string or native Python value, already
+/// extracted from the Python argument. Used both for per-call evaluation
+/// input and for the policy data document.
+///
+/// The native-value variant is kept as `serde_json::Value` rather than
+/// `regorus::Value` so that it stays `Send`: `evaluate_batch` holds a
+/// `Vec<Input>` across a `Python::allow_threads` boundary, and
+/// `regorus::Value` uses `Rc` internally for cheap cloning and is not
+/// `Send`. The conversion to `regorus::Value` happens in `to_regorus`,
+/// right before handing the value to the engine — a plain in-memory
+/// structural walk, not a stringify/reparse round trip.
+enum Input {
+    Json(String),
+    Value(serde_json::Value),
+}
+
+/// Transcode a `serde_json::Value` into the `regorus::Value` the engine
+/// actually expects. Structural walk only — no JSON text is produced.
+fn to_regorus(v: &serde_json::Value) -> regorus::Value {
+    match v {
+        serde_json::Value::Null => regorus::Value::Null,
+        serde_json::Value::Bool(b) => regorus::Value::from(*b),
+        serde_json::Value::Number(n) => match n.as_i64() {
+            Some(i) => regorus::Value::from(i),
+            None => regorus::Value::from(n.as_f64().unwrap()),
+        },
+        serde_json::Value::String(s) => regorus::Value::from(s.clone()),
+        serde_json::Value::Array(arr) => {
+            regorus::Value::from_array(arr.iter().map(to_regorus).collect())
+        }
+        serde_json::Value::Object(map) => {
+            let mut out = std::collections::BTreeMap::new();
+            for (k, val) in map {
+                out.insert(regorus::Value::from(k.clone()), to_regorus(val));
+            }
+            regorus::Value::from_map(out)
+        }
+    }
+}
+
+fn set_input(engine: &mut regorus::Engine, input: &Input) -> Result<(), OpaError> {
+    match input {
+        Input::Json(s) => engine
+            .set_input_json(s)
+            .map_err(|e| OpaError::Input(format!("{e:#}"))),
+        Input::Value(v) => {
+            engine.set_input(to_regorus(v));
+            Ok(())
+        }
+    }
+}
+
+fn add_data(engine: &mut regorus::Engine, data: &Input) -> Result<(), OpaError> {
+    match data {
+        Input::Json(s) => engine
+            .add_data_json(s)
+            .map_err(|e| OpaError::Input(format!("{e:#}"))),
+        Input::Value(v) => engine
+            .add_data(to_regorus(v))
+            .map_err(|e| OpaError::Input(format!("{e:#}"))),
+    }
+}
+
+fn do_eval(input: &Input) -> Result<String, OpaError> {
     let guard = POLICY.read().unwrap();
-    let cfg = guard.as_ref().ok_or("call load_policy() first")?;
-    let ver = POLICY_VERSION.load(Ordering::Acquire);
+    let cfg = guard.as_ref().ok_or(OpaError::NotLoaded)?;
+    let policy_ver = POLICY_GEN.load(Ordering::Acquire);
+    let data_ver = DATA_GEN.load(Ordering::Acquire);
     let query = cfg.query.clone();
 
     CACHED_ENGINE.with(|cell| {
         let mut slot = cell.borrow_mut();
+        refresh_engine(&mut slot, cfg, policy_ver, data_ver)?;
+        let (_, _, engine) = slot.as_mut().unwrap();
 
-        // Rebuild engine only when policy version changed or first call
-        let needs_rebuild = match *slot {
-            Some((v, _)) if v == ver => false,
-            _ => true,
-        };
-        if needs_rebuild {
-            *slot = Some((ver, build_engine(cfg)?));
-        }
-
-        let (_, engine) = slot.as_mut().unwrap();
-
-        engine
-            .set_input_json(input_json)
-            .map_err(|e| format!("{e:#}"))?;
+        set_input(engine, input)?;
         let value = engine
             .eval_rule(query)
-            .map_err(|e| format!("{e:#}"))?;
+            .map_err(|e| OpaError::Eval(format!("{e:#}")))?;
         Ok(value.to_string())
     })
 }
 
+/// Evaluate many inputs against the loaded policy in one call, reusing a
+/// single read-lock, version check and engine-cache lookup for the whole
+/// batch instead of paying that cost once per item (the bulk of the
+/// per-call overhead when looping `evaluate()` from Python).
+/// Per-item results: a malformed or failing input in the middle of a
+/// burst shouldn't invalidate every other item in the batch, so each
+/// input gets its own `Result` instead of aborting the whole batch on
+/// the first failure. The outer `Result` only covers batch-wide setup
+/// (no policy loaded, engine rebuild failure).
+fn do_eval_batch(inputs: &[Input]) -> Result<Vec<Result<String, OpaError>>, OpaError> {
+    let guard = POLICY.read().unwrap();
+    let cfg = guard.as_ref().ok_or(OpaError::NotLoaded)?;
+    let policy_ver = POLICY_GEN.load(Ordering::Acquire);
+    let data_ver = DATA_GEN.load(Ordering::Acquire);
+    let query = cfg.query.clone();
+
+    CACHED_ENGINE.with(|cell| {
+        let mut slot = cell.borrow_mut();
+        refresh_engine(&mut slot, cfg, policy_ver, data_ver)?;
+        let (_, _, engine) = slot.as_mut().unwrap();
+
+        Ok(inputs
+            .iter()
+            .map(|input| -> Result<String, OpaError> {
+                set_input(engine, input)?;
+                let value = engine
+                    .eval_rule(query.clone())
+                    .map_err(|e| OpaError::Eval(format!("{e:#}")))?;
+                Ok(value.to_string())
+            })
+            .collect())
+    })
+}
+
+/// Same as `do_eval`, but also gathers any `print()` output emitted by
+/// the policy during this evaluation. Gathering is toggled off again
+/// once the prints are drained so a plain `evaluate()` on the same
+/// cached engine doesn't pay for collection it doesn't use.
+fn do_eval_with_prints(input: &Input) -> Result<(String, Vec<String>), OpaError> {
+    let guard = POLICY.read().unwrap();
+    let cfg = guard.as_ref().ok_or(OpaError::NotLoaded)?;
+    let policy_ver = POLICY_GEN.load(Ordering::Acquire);
+    let data_ver = DATA_GEN.load(Ordering::Acquire);
+    let query = cfg.query.clone();
+
+    CACHED_ENGINE.with(|cell| {
+        let mut slot = cell.borrow_mut();
+        refresh_engine(&mut slot, cfg, policy_ver, data_ver)?;
+        let (_, _, engine) = slot.as_mut().unwrap();
+
+        engine.set_gather_prints(true);
+        let result = set_input(engine, input).and_then(|()| {
+            engine
+                .eval_rule(query)
+                .map(|value| value.to_string())
+                .map_err(|e| OpaError::Eval(format!("{e:#}")))
+        });
+        // Always drain and disable on every exit path — if this only ran
+        // on the happy path, an error from set_input/eval_rule above
+        // would leave gather-prints enabled and the buffer undrained on
+        // the cached engine, corrupting later evaluate()/
+        // evaluate_with_prints() calls on this thread with stale prints.
+        let prints = engine.take_prints();
+        engine.set_gather_prints(false);
+        let result = result?;
+        let prints = prints.map_err(|e| OpaError::Eval(format!("{e:#}")))?;
+        Ok((result, prints))
+    })
+}
+
+/// Fetch the coverage report (covered/uncovered lines per policy path)
+/// from the calling thread's cached engine. Requires `set_coverage(True)`
+/// to have been set before the engine was (re)built.
+fn do_get_coverage_report() -> Result<serde_json::Value, OpaError> {
+    let guard = POLICY.read().unwrap();
+    let cfg = guard.as_ref().ok_or(OpaError::NotLoaded)?;
+    let policy_ver = POLICY_GEN.load(Ordering::Acquire);
+    let data_ver = DATA_GEN.load(Ordering::Acquire);
+
+    CACHED_ENGINE.with(|cell| {
+        let mut slot = cell.borrow_mut();
+        refresh_engine(&mut slot, cfg, policy_ver, data_ver)?;
+        let (_, _, engine) = slot.as_mut().unwrap();
+
+        let report = engine
+            .get_coverage_report()
+            .map_err(|e| OpaError::Eval(format!("{e:#}")))?;
+        serde_json::to_value(&report).map_err(|e| OpaError::Eval(format!("{e:#}")))
+    })
+}
+
 // ── JSON → Python conversion (no Python json module) ────────
 
 fn json_to_py(py: Python<'_>, v: &serde_json::Value) -> PyResult<PyObject> {
@@ -101,6 +325,63 @@ fn json_to_py(py: Python<'_>, v: &serde_json::Value) -> PyResult<PyObject> {
     }
 }
 
+// ── Python → JSON conversion (the inverse of json_to_py) ────
+
+/// Walk a native Python dict/list/scalar and build the equivalent
+/// `serde_json::Value`, so callers can pass request bodies straight
+/// through without a `json.dumps()` round trip first.
+fn py_to_value(obj: &Bound<'_, PyAny>) -> PyResult<serde_json::Value> {
+    if obj.is_none() {
+        return Ok(serde_json::Value::Null);
+    }
+    // bool before int: in Python, bool is a subclass of int.
+    if let Ok(b) = obj.extract::<bool>() {
+        return Ok(serde_json::Value::Bool(b));
+    }
+    if let Ok(i) = obj.extract::<i64>() {
+        return Ok(serde_json::Value::Number(i.into()));
+    }
+    if let Ok(f) = obj.extract::<f64>() {
+        return serde_json::Number::from_f64(f)
+            .map(serde_json::Value::Number)
+            .ok_or_else(|| InputError::new_err(format!("{f} is not valid JSON (NaN/Infinity)")));
+    }
+    if let Ok(s) = obj.extract::<String>() {
+        return Ok(serde_json::Value::String(s));
+    }
+    if let Ok(list) = obj.downcast::<PyList>() {
+        let items = list
+            .iter()
+            .map(|item| py_to_value(&item))
+            .collect::<PyResult<Vec<_>>>()?;
+        return Ok(serde_json::Value::Array(items));
+    }
+    if let Ok(dict) = obj.downcast::<PyDict>() {
+        let mut map = serde_json::Map::new();
+        for (k, v) in dict.iter() {
+            let key: String = k.extract().map_err(|_| {
+                InputError::new_err("dict keys must be strings to convert to JSON")
+            })?;
+            map.insert(key, py_to_value(&v)?);
+        }
+        return Ok(serde_json::Value::Object(map));
+    }
+    Err(InputError::new_err(format!(
+        "unsupported input type: {}",
+        obj.get_type().name()?
+    )))
+}
+
+/// Accept either a raw JSON string or a native dict/list as evaluation
+/// input, without forcing every caller through `json.dumps()` first.
+fn extract_input(obj: &Bound<'_, PyAny>) -> PyResult<Input> {
+    if let Ok(s) = obj.extract::<String>() {
+        Ok(Input::Json(s))
+    } else {
+        Ok(Input::Value(py_to_value(obj)?))
+    }
+}
+
 /// OPA policy evaluator using regorus.
 ///
 /// Usage:
@@ -109,54 +390,218 @@ fn json_to_py(py: Python<'_>, v: &serde_json::Value) -> PyResult<PyObject> {
 ///     result = opa_eval.evaluate('{"role": "admin"}')
 #[pymodule]
 fn opa_eval(m: &Bound<'_, PyModule>) -> PyResult<()> {
-    /// Load a .rego policy file.
+    m.add("PolicyError", m.py().get_type::<PolicyError>())?;
+    m.add("PolicyLoadError", m.py().get_type::<PolicyLoadError>())?;
+    m.add("PolicyParseError", m.py().get_type::<PolicyParseError>())?;
+    m.add("PolicyNotLoadedError", m.py().get_type::<PolicyNotLoadedError>())?;
+    m.add("EvaluationError", m.py().get_type::<EvaluationError>())?;
+    m.add("InputError", m.py().get_type::<InputError>())?;
+
+    /// Load a .rego policy file, replacing any previously loaded policy
+    /// and data. Forces a full engine rebuild.
     ///
     /// Args:
     ///     policy_path: Path to a .rego file.
-    ///     data_json:   Optional JSON string for external data.
+    ///     data:        Optional external data — a JSON string or a
+    ///                  native dict/list.
     ///     query:       Rego query to evaluate (default: "data").
     #[pyfn(m)]
-    #[pyo3(signature = (policy_path, data_json=None, query=None))]
+    #[pyo3(signature = (policy_path, data=None, query=None))]
     fn load_policy(
         policy_path: &str,
-        data_json: Option<String>,
+        data: Option<&Bound<'_, PyAny>>,
         query: Option<String>,
     ) -> PyResult<()> {
         let source = std::fs::read_to_string(policy_path)
-            .map_err(|e| PyRuntimeError::new_err(format!("failed to read {policy_path}: {e}")))?;
+            .map_err(|e| OpaError::Load(format!("failed to read {policy_path}: {e}")))?;
 
         // Validate the policy parses
         let mut engine = regorus::Engine::new();
         engine
             .add_policy(policy_path.to_string(), source.clone())
-            .map_err(|e| PyRuntimeError::new_err(format!("invalid policy: {e:#}")))?;
+            .map_err(|e| OpaError::Parse(format!("invalid policy: {e:#}")))?;
+
+        let data = data.map(extract_input).transpose()?;
 
-        *POLICY.write().unwrap() = Some(PolicyConfig {
-            path: policy_path.to_string(),
-            source,
-            data_json,
+        // Hold the write guard across both generation bumps so a concurrent
+        // reader can never observe the new config paired with a stale
+        // generation number (which would match it to the old cached engine).
+        let mut guard = POLICY.write().unwrap();
+        *guard = Some(PolicyConfig {
+            modules: vec![(policy_path.to_string(), source)],
+            data,
             query: query.unwrap_or_else(|| "data".to_string()),
         });
-        // Bump version so thread-local caches rebuild
-        POLICY_VERSION.fetch_add(1, Ordering::Release);
+        // Both the module set and the data changed.
+        POLICY_GEN.fetch_add(1, Ordering::Release);
+        DATA_GEN.fetch_add(1, Ordering::Release);
+        drop(guard);
+        Ok(())
+    }
+
+    /// Register an additional .rego module under its own path name,
+    /// on top of whatever is already loaded. `load_policy()` must have
+    /// been called first. Forces a full engine rebuild (policy-gen bump).
+    ///
+    /// Args:
+    ///     policy_path: Path name the module is registered under.
+    ///     source:      Rego source text.
+    #[pyfn(m)]
+    fn add_policy(policy_path: &str, source: &str) -> PyResult<()> {
+        let mut guard = POLICY.write().unwrap();
+        let cfg = guard.as_mut().ok_or(OpaError::NotLoaded)?;
+
+        // Validate against the full combined module set, not just this
+        // module in isolation — a module can parse fine on its own yet
+        // conflict with (or fail to resolve against) packages already
+        // loaded, and that must fail here rather than on the hot path.
+        let mut check = regorus::Engine::new();
+        for (path, existing_source) in &cfg.modules {
+            check
+                .add_policy(path.clone(), existing_source.clone())
+                .map_err(|e| OpaError::Parse(format!("invalid policy: {e:#}")))?;
+        }
+        check
+            .add_policy(policy_path.to_string(), source.to_string())
+            .map_err(|e| OpaError::Parse(format!("invalid policy: {e:#}")))?;
+
+        cfg.modules.push((policy_path.to_string(), source.to_string()));
+        POLICY_GEN.fetch_add(1, Ordering::Release);
         Ok(())
     }
 
-    /// Evaluate the loaded policy with the given input JSON string.
+    /// Replace the external data document without touching loaded
+    /// policy modules. Only bumps the data generation, so cached
+    /// engines refresh their data instead of reparsing Rego.
+    ///
+    /// Args:
+    ///     data: A JSON string or a native dict/list.
+    #[pyfn(m)]
+    fn update_data(data: &Bound<'_, PyAny>) -> PyResult<()> {
+        let data = extract_input(data)?;
+        let mut guard = POLICY.write().unwrap();
+        let cfg = guard.as_mut().ok_or(OpaError::NotLoaded)?;
+        cfg.data = Some(data);
+        DATA_GEN.fetch_add(1, Ordering::Release);
+        Ok(())
+    }
+
+    /// Clear the external data document, keeping loaded policy modules.
+    #[pyfn(m)]
+    fn clear_data() -> PyResult<()> {
+        let mut guard = POLICY.write().unwrap();
+        let cfg = guard.as_mut().ok_or(OpaError::NotLoaded)?;
+        cfg.data = None;
+        DATA_GEN.fetch_add(1, Ordering::Release);
+        Ok(())
+    }
+
+    /// Evaluate the loaded policy with the given input.
+    /// `input` may be a JSON string, or a native dict/list — the latter
+    /// skips a `json.dumps()`/parse round trip on the hot path.
     /// Returns the result as a JSON string.
     /// Thread-safe — each thread caches its own engine instance.
     #[pyfn(m)]
-    fn evaluate(input_json: &str) -> PyResult<String> {
-        do_eval(input_json).map_err(|e| PyRuntimeError::new_err(e))
+    fn evaluate(input: &Bound<'_, PyAny>) -> PyResult<String> {
+        Ok(do_eval(&extract_input(input)?)?)
     }
 
     /// Evaluate and return parsed Python object directly.
     /// Converts JSON → Python in Rust (no Python json module overhead).
     #[pyfn(m)]
-    fn evaluate_parsed(py: Python<'_>, input_json: &str) -> PyResult<PyObject> {
-        let json_str = do_eval(input_json).map_err(|e| PyRuntimeError::new_err(e))?;
+    fn evaluate_parsed(py: Python<'_>, input: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        let json_str = do_eval(&extract_input(input)?)?;
         let value: serde_json::Value = serde_json::from_str(&json_str)
-            .map_err(|e| PyRuntimeError::new_err(format!("invalid result JSON: {e}")))?;
+            .map_err(|e| EvaluationError::new_err(format!("invalid result JSON: {e}")))?;
+        json_to_py(py, &value)
+    }
+
+    /// Evaluate like `evaluate()`, but also return any `print()` output
+    /// emitted by the policy while handling this input. Useful for
+    /// debugging Rego rules interactively, where `print()` otherwise
+    /// vanishes with no trace.
+    ///
+    /// Returns a `(result_json, prints)` tuple.
+    #[pyfn(m)]
+    fn evaluate_with_prints(input: &Bound<'_, PyAny>) -> PyResult<(String, Vec<String>)> {
+        Ok(do_eval_with_prints(&extract_input(input)?)?)
+    }
+
+    /// Evaluate the loaded policy against many inputs at once, reusing
+    /// one thread-local engine for the whole batch. Each item of
+    /// `inputs` may be a JSON string or a native dict/list.
+    ///
+    /// A failing item does not abort the batch: the corresponding
+    /// position in the returned list holds the exception instance
+    /// (e.g. an `EvaluationError`) instead of a result, so one malformed
+    /// request in a burst doesn't lose every other result.
+    ///
+    /// Args:
+    ///     inputs: List of inputs to evaluate.
+    ///     parsed: If True, return parsed Python objects instead of
+    ///             JSON strings (mirrors `evaluate_parsed`).
+    #[pyfn(m)]
+    #[pyo3(signature = (inputs, parsed=false))]
+    fn evaluate_batch(
+        py: Python<'_>,
+        inputs: &Bound<'_, PyList>,
+        parsed: bool,
+    ) -> PyResult<PyObject> {
+        // An item that cannot be converted keeps its own error in its slot
+        // rather than raising for the whole batch.
+        let mut conversion_errors: Vec<Option<PyErr>> = Vec::new();
+        let mut converted: Vec<Input> = Vec::new();
+        for item in inputs.iter() {
+            match extract_input(&item) {
+                Ok(input) => {
+                    converted.push(input);
+                    conversion_errors.push(None);
+                }
+                Err(e) => conversion_errors.push(Some(e)),
+            }
+        }
+
+        let mut results = py.allow_threads(|| do_eval_batch(&converted))?.into_iter();
+
+        let items: Vec<PyObject> = conversion_errors
+            .into_iter()
+            .map(|conversion_error| {
+                let result = match conversion_error {
+                    Some(e) => Err(e),
+                    None => results.next().unwrap().map_err(PyErr::from),
+                };
+                match result {
+                    Ok(json_str) if parsed => {
+                        let value: serde_json::Value = serde_json::from_str(&json_str).map_err(|e| {
+                            EvaluationError::new_err(format!("invalid result JSON: {e}"))
+                        })?;
+                        json_to_py(py, &value)
+                    }
+                    Ok(json_str) => Ok(PyString::new(py, &json_str).into_any().unbind()),
+                    Err(e) => Ok(e.value(py).clone().into_any().unbind()),
+                }
+            })
+            .collect::<PyResult<_>>()?;
+        Ok(PyList::new(py, &items)?.into_any().unbind())
+    }
+
+    /// Enable or disable coverage instrumentation (which policy lines
+    /// were exercised during evaluation). This is baked into the engine
+    /// at build time, so toggling it forces a full rebuild on every
+    /// thread's cached engine, same as a policy change.
+    #[pyfn(m)]
+    fn set_coverage(enabled: bool) -> PyResult<()> {
+        COVERAGE_ENABLED.store(enabled, Ordering::Release);
+        POLICY_GEN.fetch_add(1, Ordering::Release);
+        Ok(())
+    }
+
+    /// Return the coverage report accumulated so far on this thread's
+    /// cached engine: per policy path, which lines were covered and
+    /// which weren't. Requires `set_coverage(True)`.
+    #[pyfn(m)]
+    fn get_coverage_report(py: Python<'_>) -> PyResult<PyObject> {
+        let value = do_get_coverage_report()?;
         json_to_py(py, &value)
     }
 